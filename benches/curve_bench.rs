@@ -0,0 +1,24 @@
+use std::str::FromStr;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use walletcryptography::curve::WeierstrassCurve;
+use walletcryptography::ru256::RU256;
+use walletcryptography::secp256k1::SECP256K1;
+
+// Benchmarks the Jacobian-coordinate scalar multiplication that
+// `SECP256K1::pr_to_pub` now uses. The previous affine-formula path
+// (one field inversion per add/double) has been removed, so this is
+// a baseline to catch future regressions rather than a live A/B
+// comparison -- see the commit that introduced `jacobian.rs` for the
+// before/after numbers.
+fn pr_to_pub_benchmark(c: &mut Criterion) {
+    let scalar = RU256::from_str("B6D4F1B6C5E42EE9B8B7F1F3E3C2C1A0FEDCBA9876543210FEDCBA9876543210").unwrap();
+
+    c.bench_function("secp256k1_pr_to_pub_jacobian", |b| {
+        b.iter(|| SECP256K1::pr_to_pub(black_box(&scalar)));
+    });
+}
+
+criterion_group!(benches, pr_to_pub_benchmark);
+criterion_main!(benches);
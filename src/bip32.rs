@@ -0,0 +1,269 @@
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha256, Sha512};
+use std::str::FromStr;
+
+use crate::curve::{Point, WeierstrassCurve};
+use crate::ru256::RU256;
+use crate::secp256k1::SECP256K1;
+
+type HmacSha512 = Hmac<Sha512>;
+
+// ******************************************************************
+// BIP32 hierarchical deterministic key derivation
+// Reference: https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki
+//
+// Only secp256k1 is in scope here (BIP32 is specific to it), so this
+// module hangs off `SECP256K1` directly rather than being generic over
+// `WeierstrassCurve`.
+// ******************************************************************
+
+pub const HARDENED_OFFSET: u32 = 0x8000_0000;
+
+const XPRV_VERSION: u32 = 0x0488ADE4;
+const XPUB_VERSION: u32 = 0x0488B21E;
+
+#[derive(Debug, PartialEq)]
+pub enum Bip32Error {
+    InvalidSeedLength,
+    InvalidMasterKey,
+    InvalidChildKey
+}
+
+#[derive(Debug, Clone)]
+pub struct ExtendedPrivKey {
+    pub key: RU256,
+    pub chain_code: [u8; 32],
+    pub depth: u8,
+    pub child_number: u32,
+    pub parent_fingerprint: [u8; 4]
+}
+
+#[derive(Debug, Clone)]
+pub struct ExtendedPubKey {
+    pub key: Point,
+    pub chain_code: [u8; 32],
+    pub depth: u8,
+    pub child_number: u32,
+    pub parent_fingerprint: [u8; 4]
+}
+
+impl ExtendedPrivKey {
+
+    pub fn from_seed(seed: &[u8]) -> Result<Self, Bip32Error> {
+        if seed.len() < 16 || seed.len() > 64 {
+            return Err(Bip32Error::InvalidSeedLength);
+        }
+
+        let n = &SECP256K1::n();
+        let i = hmac_sha512(b"Bitcoin seed", seed);
+        let (il, ir) = i.split_at(32);
+
+        let key = RU256::from_bytes(il);
+        if key >= *n || key == RU256::from_str("0x0").unwrap() {
+            return Err(Bip32Error::InvalidMasterKey);
+        }
+
+        let mut chain_code: [u8; 32] = [0; 32];
+        chain_code.copy_from_slice(ir);
+
+        return Ok(ExtendedPrivKey {
+            key,
+            chain_code,
+            depth: 0,
+            child_number: 0,
+            parent_fingerprint: [0; 4]
+        });
+    }
+
+    pub fn public_key(&self) -> Point {
+        return SECP256K1::pr_to_pub(&self.key);
+    }
+
+    // ******************************************************************
+    // Child key derivation (CKDpriv)
+    // **NOTE: hardened indices (index >= 2^31) mix in the private key
+    //         bytes; normal indices mix in the compressed public key
+    //         instead, so normal children can be derived from an xpub
+    //         alone (see `ExtendedPubKey::derive_child`).
+    // ******************************************************************
+
+    pub fn derive_child(&self, index: u32) -> Result<Self, Bip32Error> {
+        let n = &SECP256K1::n();
+        let hardened = index >= HARDENED_OFFSET;
+
+        let mut data: Vec<u8> = Vec::with_capacity(37);
+        if hardened {
+            data.push(0x00);
+            let mut key_bytes: [u8; 32] = [0; 32];
+            self.key.to_bytes(&mut key_bytes);
+            data.extend_from_slice(&key_bytes);
+        } else {
+            data.extend_from_slice(&compressed_bytes(&self.public_key()));
+        }
+        data.extend_from_slice(&index.to_be_bytes());
+
+        let i = hmac_sha512(&self.chain_code, &data);
+        let (il_bytes, ir_bytes) = i.split_at(32);
+        let il = RU256::from_bytes(il_bytes);
+
+        if il >= *n {
+            return Err(Bip32Error::InvalidChildKey);
+        }
+
+        let child_key = il.add_mod(&self.key, n);
+        if child_key == RU256::from_str("0x0").unwrap() {
+            return Err(Bip32Error::InvalidChildKey);
+        }
+
+        let mut chain_code: [u8; 32] = [0; 32];
+        chain_code.copy_from_slice(ir_bytes);
+
+        return Ok(ExtendedPrivKey {
+            key: child_key,
+            chain_code,
+            depth: self.depth + 1,
+            child_number: index,
+            parent_fingerprint: fingerprint(&self.public_key())
+        });
+    }
+
+    pub fn to_xprv(&self) -> String {
+        let mut key_data: [u8; 33] = [0; 33];
+        self.key.to_bytes((&mut key_data[1..33]).try_into().unwrap());
+
+        return serialize_extended_key(
+            XPRV_VERSION, self.depth, &self.parent_fingerprint,
+            self.child_number, &self.chain_code, &key_data
+        );
+    }
+}
+
+impl ExtendedPubKey {
+
+    pub fn from_private(xpriv: &ExtendedPrivKey) -> Self {
+        return ExtendedPubKey {
+            key: xpriv.public_key(),
+            chain_code: xpriv.chain_code,
+            depth: xpriv.depth,
+            child_number: xpriv.child_number,
+            parent_fingerprint: xpriv.parent_fingerprint
+        };
+    }
+
+    // Public (non-hardened) child derivation (CKDpub): child_pub = IL*G + parent_pub
+    pub fn derive_child(&self, index: u32) -> Result<Self, Bip32Error> {
+        if index >= HARDENED_OFFSET {
+            return Err(Bip32Error::InvalidChildKey);
+        }
+
+        let n = &SECP256K1::n();
+
+        let mut data: Vec<u8> = Vec::with_capacity(37);
+        data.extend_from_slice(&compressed_bytes(&self.key));
+        data.extend_from_slice(&index.to_be_bytes());
+
+        let i = hmac_sha512(&self.chain_code, &data);
+        let (il_bytes, ir_bytes) = i.split_at(32);
+        let il = RU256::from_bytes(il_bytes);
+
+        if il >= *n {
+            return Err(Bip32Error::InvalidChildKey);
+        }
+
+        let il_g = SECP256K1::pr_to_pub(&il);
+        let child_pub = SECP256K1::add_points(&il_g, &self.key);
+        if child_pub.is_zero_point() {
+            return Err(Bip32Error::InvalidChildKey);
+        }
+
+        let mut chain_code: [u8; 32] = [0; 32];
+        chain_code.copy_from_slice(ir_bytes);
+
+        return Ok(ExtendedPubKey {
+            key: child_pub,
+            chain_code,
+            depth: self.depth + 1,
+            child_number: index,
+            parent_fingerprint: fingerprint(&self.key)
+        });
+    }
+
+    pub fn to_xpub(&self) -> String {
+        return serialize_extended_key(
+            XPUB_VERSION, self.depth, &self.parent_fingerprint,
+            self.child_number, &self.chain_code, &compressed_bytes(&self.key)
+        );
+    }
+}
+
+fn compressed_bytes(point: &Point) -> [u8; 33] {
+    let mut bytes: [u8; 33] = [0; 33];
+    bytes.copy_from_slice(&hex::decode(point.to_compressed_hex()).unwrap());
+    return bytes;
+}
+
+fn fingerprint(point: &Point) -> [u8; 4] {
+    let mut fp: [u8; 4] = [0; 4];
+    fp.copy_from_slice(&hash160(&compressed_bytes(point))[0..4]);
+    return fp;
+}
+
+fn hash160(data: &[u8]) -> [u8; 20] {
+    let sha = Sha256::digest(data);
+    let ripemd = ripemd::Ripemd160::digest(&sha);
+    return ripemd.into();
+}
+
+fn hmac_sha512(key: &[u8], data: &[u8]) -> [u8; 64] {
+    let mut mac = HmacSha512::new_from_slice(key).expect("HMAC accepts any key length");
+    mac.update(data);
+    return mac.finalize().into_bytes().into();
+}
+
+// Base58Check: version || depth || parent_fingerprint || child_number || chain_code || key_data || checksum(4)
+fn serialize_extended_key(
+    version: u32, depth: u8, parent_fingerprint: &[u8; 4],
+    child_number: u32, chain_code: &[u8; 32], key_data: &[u8; 33]
+) -> String {
+    let mut payload: Vec<u8> = Vec::with_capacity(78);
+    payload.extend_from_slice(&version.to_be_bytes());
+    payload.push(depth);
+    payload.extend_from_slice(parent_fingerprint);
+    payload.extend_from_slice(&child_number.to_be_bytes());
+    payload.extend_from_slice(chain_code);
+    payload.extend_from_slice(key_data);
+
+    let checksum = Sha256::digest(&Sha256::digest(&payload));
+    payload.extend_from_slice(&checksum[0..4]);
+
+    return bs58::encode(payload).into_string();
+}
+
+
+
+mod tests {
+    use super::*;
+
+    // BIP32 test vector 1 (https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki)
+    #[test]
+    fn bip32_test_vector_1_master_key() {
+        let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+        let master = ExtendedPrivKey::from_seed(&seed).unwrap();
+        let master_pub = ExtendedPubKey::from_private(&master);
+
+        assert_eq!(master.to_xprv(), "xprv9s21ZrQH143K3QTDL4LXw2F7HEK3wJUD2nW2nRk4stbPy6cq3jPPqjiChkVvvNKmPGJxWUtg6LnF5kejMRNNU3TGtRBeJgk33yuGBxrMPHi");
+        assert_eq!(master_pub.to_xpub(), "xpub661MyMwAqRbcFtXgS5sYJABqqG9YLmC4Q1Rdap9gSE8NqtwybGhePY2gZ29ESFjqJoCu1Rupje8YtGqsefD265TMg7usUDFdp6W1EGMcet8");
+    }
+
+    #[test]
+    fn bip32_test_vector_1_hardened_child() {
+        let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+        let master = ExtendedPrivKey::from_seed(&seed).unwrap();
+
+        let child = master.derive_child(HARDENED_OFFSET).unwrap();
+        let child_pub = ExtendedPubKey::from_private(&child);
+
+        assert_eq!(child.to_xprv(), "xprv9uHRZZhk6KAJC1avXpDAp4MDc3sQKNxDiPvvkX8Br5ngLNv1TxvUxt4cV1rGL5hj6KCesnDYUhd7oWgT11eZG7XnxHrnYeSvkzY7d2bhkJ7");
+        assert_eq!(child_pub.to_xpub(), "xpub68Gmy5EdvgibQVfPdqkBBCHxA5htiqg55crXYuXoQRKfDBFA1WEjWgP6LHhwBZeNK1VTsfTFUHCdrfp1bgwQ9xv5ski8PX9rL2dZXvgGDnw");
+    }
+}
@@ -0,0 +1,127 @@
+use std::str::FromStr;
+use crate::curve::{Point, WeierstrassCurve};
+use crate::ru256::RU256;
+
+// ******************************************************************
+// Jacobian projective coordinates: affine (x, y) <-> (X/Z^2, Y/Z^3)
+//
+// Addition and doubling only need field multiplications, so a full
+// scalar multiplication needs exactly one inversion (at the very end,
+// converting back to affine) instead of one per step.
+// ******************************************************************
+
+#[derive(Clone)]
+pub struct JacobianPoint {
+    x: RU256,
+    y: RU256,
+    z: RU256
+}
+
+impl JacobianPoint {
+    pub fn identity() -> Self {
+        return JacobianPoint {
+            x: RU256::from_str("0x1").unwrap(),
+            y: RU256::from_str("0x1").unwrap(),
+            z: RU256::from_str("0x0").unwrap()
+        };
+    }
+
+    pub fn is_identity(&self) -> bool {
+        return self.z == RU256::from_str("0x0").unwrap();
+    }
+
+    pub fn from_affine(pt: &Point) -> Self {
+        if pt.is_zero_point() {
+            return Self::identity();
+        }
+        return JacobianPoint { x: pt.x.clone(), y: pt.y.clone(), z: RU256::from_str("0x1").unwrap() };
+    }
+
+    pub fn to_affine<C: WeierstrassCurve>(&self) -> Point {
+        if self.is_identity() {
+            return C::zero_point();
+        }
+
+        let p = &C::p();
+        let one = &RU256::from_str("0x1").unwrap();
+
+        let z_inv = &one.div_mod(&self.z, p);
+        let z_inv2 = &z_inv.mul_mod(z_inv, p);
+        let z_inv3 = &z_inv2.mul_mod(z_inv, p);
+
+        return Point {
+            x: self.x.mul_mod(z_inv2, p),
+            y: self.y.mul_mod(z_inv3, p)
+        };
+    }
+
+    // Doubling, generic over `a` (reduces to the well-known `a = 0`
+    // secp256k1 shortcut E = 3A when the curve's `a` is zero):
+    //
+    // A = X^2, B = Y^2, C = B^2
+    // D = 2*((X+B)^2 - A - C)
+    // E = 3*A + a*Z^4
+    // F = E^2
+    // X' = F - 2D, Y' = E*(D - X') - 8C, Z' = 2*Y*Z
+    pub fn double<C: WeierstrassCurve>(&self) -> Self {
+        if self.is_identity() || self.y == RU256::from_str("0x0").unwrap() {
+            return Self::identity();
+        }
+
+        let p = &C::p();
+        let two = &RU256::from_str("0x2").unwrap();
+        let three = &RU256::from_str("0x3").unwrap();
+        let eight = &RU256::from_str("0x8").unwrap();
+
+        let a = &self.x.mul_mod(&self.x, p);
+        let b = &self.y.mul_mod(&self.y, p);
+        let c = &b.mul_mod(b, p);
+        let x_plus_b = &self.x.add_mod(b, p);
+        let d = &x_plus_b.mul_mod(x_plus_b, p).sub_mod(a, p).sub_mod(c, p).mul_mod(two, p);
+        let z2 = &self.z.mul_mod(&self.z, p);
+        let z4 = &z2.mul_mod(z2, p);
+        let e = &a.mul_mod(three, p).add_mod(&C::a().mul_mod(z4, p), p);
+        let f = &e.mul_mod(e, p);
+
+        let x3 = f.sub_mod(&d.mul_mod(two, p), p);
+        let y3 = e.mul_mod(&d.sub_mod(&x3, p), p).sub_mod(&c.mul_mod(eight, p), p);
+        let z3 = two.mul_mod(&self.y, p).mul_mod(&self.z, p);
+
+        return JacobianPoint { x: x3, y: y3, z: z3 };
+    }
+
+    // General addition (curve-coefficient-independent). Detects the
+    // doubling and mutual-inverse (identity) cases itself instead of
+    // relying on the caller to never pass equal/opposite points, which
+    // is the affine-formula kludge this replaces.
+    pub fn add<C: WeierstrassCurve>(&self, other: &Self) -> Self {
+        if self.is_identity() { return other.clone(); }
+        if other.is_identity() { return self.clone(); }
+
+        let p = &C::p();
+
+        let z1z1 = &self.z.mul_mod(&self.z, p);
+        let z2z2 = &other.z.mul_mod(&other.z, p);
+        let u1 = &self.x.mul_mod(z2z2, p);
+        let u2 = &other.x.mul_mod(z1z1, p);
+        let s1 = &self.y.mul_mod(&other.z, p).mul_mod(z2z2, p);
+        let s2 = &other.y.mul_mod(&self.z, p).mul_mod(z1z1, p);
+
+        if u1 == u2 {
+            return if s1 == s2 { self.double::<C>() } else { Self::identity() };
+        }
+
+        let h = &u2.sub_mod(u1, p);
+        let r = &s2.sub_mod(s1, p);
+        let hh = &h.mul_mod(h, p);
+        let hhh = &h.mul_mod(hh, p);
+        let v = &u1.mul_mod(hh, p);
+
+        let two = &RU256::from_str("0x2").unwrap();
+        let x3 = r.mul_mod(r, p).sub_mod(hhh, p).sub_mod(&v.mul_mod(two, p), p);
+        let y3 = r.mul_mod(&v.sub_mod(&x3, p), p).sub_mod(&s1.mul_mod(hhh, p), p);
+        let z3 = self.z.mul_mod(&other.z, p).mul_mod(h, p);
+
+        return JacobianPoint { x: x3, y: y3, z: z3 };
+    }
+}
@@ -0,0 +1,99 @@
+use std::str::FromStr;
+use rand::RngCore;
+
+use crate::curve::{Point, WeierstrassCurve};
+use crate::ru256::RU256;
+use crate::secp256k1::SECP256K1;
+
+// ******************************************************************
+// SecretKey
+// **NOTE: wraps a private scalar and zeroes its backing memory on
+//         drop via a volatile write, so it isn't optimized away by
+//         the compiler and doesn't linger in freed memory. Refuses
+//         to be constructed outside of [1, n-1].
+// ******************************************************************
+
+pub struct SecretKey(RU256);
+
+#[derive(Debug, PartialEq)]
+pub enum SecretKeyError {
+    OutOfRange
+}
+
+impl SecretKey {
+    pub fn new(scalar: RU256) -> Result<Self, SecretKeyError> {
+        let n = &SECP256K1::n();
+        let zero = &RU256::from_str("0x0").unwrap();
+        if &scalar <= zero || &scalar >= n {
+            return Err(SecretKeyError::OutOfRange);
+        }
+        return Ok(SecretKey(scalar));
+    }
+
+    pub(crate) fn as_ru256(&self) -> &RU256 {
+        return &self.0;
+    }
+}
+
+impl Drop for SecretKey {
+    fn drop(&mut self) {
+        let zero = RU256::from_str("0x0").unwrap();
+        unsafe { std::ptr::write_volatile(&mut self.0 as *mut RU256, zero); }
+    }
+}
+
+// ******************************************************************
+// KeyPair
+// **NOTE: signing goes through `SecretKey::as_ru256`, a borrow, so the
+//         scalar is never copied into a separate owned value that
+//         would outlive the `SecretKey`'s own zero-on-drop guarantee.
+// ******************************************************************
+
+pub struct KeyPair {
+    pub secret: SecretKey,
+    pub public: Point
+}
+
+impl KeyPair {
+    pub fn from_secret(secret: SecretKey) -> Self {
+        let public = SECP256K1::pr_to_pub(secret.as_ru256());
+        return KeyPair { secret, public };
+    }
+
+    pub fn generate<R: RngCore>(rng: &mut R) -> Self {
+        loop {
+            let mut bytes: [u8; 32] = [0; 32];
+            rng.fill_bytes(&mut bytes);
+            if let Ok(secret) = SecretKey::new(RU256::from_bytes(&bytes)) {
+                return Self::from_secret(secret);
+            }
+        }
+    }
+
+    pub fn sign(&self, msg_hash: &RU256) -> (RU256, RU256) {
+        return SECP256K1::sign(self.secret.as_ru256(), msg_hash);
+    }
+}
+
+
+
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_out_of_range_scalars() {
+        let zero = RU256::from_str("0x0").unwrap();
+        assert!(matches!(SecretKey::new(zero), Err(SecretKeyError::OutOfRange)));
+
+        let n = SECP256K1::n();
+        assert!(matches!(SecretKey::new(n), Err(SecretKeyError::OutOfRange)));
+    }
+
+    #[test]
+    fn keypair_from_secret_derives_matching_public_key() {
+        let secret = SecretKey::new(RU256::from_str("0x1").unwrap()).unwrap();
+        let pair = KeyPair::from_secret(secret);
+
+        assert_eq!(pair.public.to_hex_string(), SECP256K1::g().to_hex_string());
+    }
+}
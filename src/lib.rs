@@ -0,0 +1,10 @@
+pub mod ru256;
+pub mod bytes;
+pub mod base16;
+pub mod curve;
+pub mod jacobian;
+pub mod secp256k1;
+pub mod secp256r1;
+pub mod rfc6979;
+pub mod bip32;
+pub mod keys;
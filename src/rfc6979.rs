@@ -0,0 +1,93 @@
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::str::FromStr;
+
+use crate::ru256::RU256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+// ******************************************************************
+// RFC 6979 deterministic nonce generation
+// Reference: https://datatracker.ietf.org/doc/html/rfc6979
+//
+// Specialized to SHA-256 and to curves whose order is 256 bits wide
+// (secp256k1/secp256r1), so `int2octets`/`bits2octets` are a plain
+// 32-byte big-endian encoding rather than the general bit-length
+// truncation the RFC describes.
+// ******************************************************************
+
+// ******************************************************************
+// Nonce generator (steps b-h)
+// **NOTE: a caller that rejects a candidate (e.g. because the resulting
+//         (r, s) turned out to be degenerate) must keep calling `next`
+//         on the SAME generator rather than starting a new one -- per
+//         §3.2.h, a rejected candidate continues the existing K/V state
+//         (K = HMAC_K(V||0x00), V = HMAC_K(V), draw a new T) instead of
+//         restarting the whole HMAC-DRBG from scratch.
+// ******************************************************************
+
+pub struct NonceGenerator {
+    k: [u8; 32],
+    v: [u8; 32],
+    n: RU256
+}
+
+impl NonceGenerator {
+    pub fn new(privkey: &RU256, msg_hash: &RU256, n: &RU256) -> Self {
+        let mut key_bytes: [u8; 32] = [0; 32];
+        privkey.to_bytes(&mut key_bytes);
+
+        let hash_bytes = bits2octets(msg_hash, n);
+
+        // b. V = 0x01 0x01 ... 0x01 (32 bytes)
+        let mut v: [u8; 32] = [0x01; 32];
+        // c. K = 0x00 0x00 ... 0x00 (32 bytes)
+        let mut k: [u8; 32] = [0x00; 32];
+
+        // d. K = HMAC_K(V || 0x00 || int2octets(d) || bits2octets(z))
+        k = hmac(&k, &[&v, &[0x00], &key_bytes, &hash_bytes]);
+        // e. V = HMAC_K(V)
+        v = hmac(&k, &[&v]);
+        // f. K = HMAC_K(V || 0x01 || int2octets(d) || bits2octets(z))
+        k = hmac(&k, &[&v, &[0x01], &key_bytes, &hash_bytes]);
+        // g. V = HMAC_K(V)
+        v = hmac(&k, &[&v]);
+
+        return NonceGenerator { k, v, n: n.clone() };
+    }
+
+    // h. generate T candidates, continuing this generator's K/V state,
+    // until one lands in [1, n-1]
+    pub fn next(&mut self) -> RU256 {
+        let zero = RU256::from_str("0x0").unwrap();
+        loop {
+            self.v = hmac(&self.k, &[&self.v]);
+            let candidate = RU256::from_bytes(&self.v);
+            // re-key before the range check, so a candidate the caller
+            // rejects later is followed by the same K/V update as one
+            // rejected here
+            self.k = hmac(&self.k, &[&self.v, &[0x00]]);
+            self.v = hmac(&self.k, &[&self.v]);
+            if candidate > zero && candidate < self.n {
+                return candidate;
+            }
+        }
+    }
+}
+
+fn hmac(key: &[u8], parts: &[&[u8]]) -> [u8; 32] {
+    let mut mac = HmacSha256::new_from_slice(key).expect("HMAC accepts any key length");
+    for part in parts {
+        mac.update(part);
+    }
+    mac.finalize().into_bytes().into()
+}
+
+// bits2octets: the hash is already exactly as wide as the curve order,
+// so this reduces to a single conditional subtraction of `n`.
+fn bits2octets(z: &RU256, n: &RU256) -> [u8; 32] {
+    let reduced = if z >= n { z.sub_mod(&RU256::from_str("0x0").unwrap(), n) } else { z.clone() };
+    let mut out: [u8; 32] = [0; 32];
+    reduced.to_bytes(&mut out);
+    out
+}
@@ -0,0 +1,73 @@
+use std::str::FromStr;
+use crate::ru256::RU256;
+use crate::curve::{Point, WeierstrassCurve};
+
+pub struct SECP256R1;
+
+impl WeierstrassCurve for SECP256R1 {
+
+    // ******************************************************************
+    // SECP256R1 (NIST P-256) Curve Parameters
+    // Reference: https://www.secg.org/sec2-v2.pdf
+    // ******************************************************************
+
+    fn p() -> RU256 {
+        return RU256::from_str("FFFFFFFF00000001000000000000000000000000FFFFFFFFFFFFFFFFFFFFFFFF").unwrap();
+    }
+    fn a() -> RU256 {
+        return RU256::from_str("FFFFFFFF00000001000000000000000000000000FFFFFFFFFFFFFFFFFFFFFFFC").unwrap();
+    }
+    fn b() -> RU256 {
+        return RU256::from_str("5AC635D8AA3A93E7B3EBBD55769886BC651D06B0CC53B0F63BCE3C3E27D2604B").unwrap();
+    }
+    fn g() -> Point {
+        return Point {
+            x: RU256::from_str("6B17D1F2E12C4247F8BCE6E563A440F277037D812DEB33A0F4A13945D898C296").unwrap(),
+            y: RU256::from_str("4FE342E2FE1A7F9B8EE7EB4A7C0F9E162BCE33576B315ECECBB6406837BF51F5").unwrap()
+        };
+    }
+    fn n() -> RU256 {
+        return RU256::from_str("FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632551").unwrap();
+    }
+    fn sqrt_exponent() -> RU256 {
+        // (p+1)/4, precomputed since p is a fixed curve constant
+        return RU256::from_str("3FFFFFFFC0000000400000000000000000000000400000000000000000000000").unwrap();
+    }
+    fn half_n() -> RU256 {
+        // floor(n/2), precomputed since n is a fixed curve constant
+        return RU256::from_str("7FFFFFFF800000007FFFFFFFFFFFFFFFDE737D56D38BCF4279DCE5617E3192A8").unwrap();
+    }
+}
+
+
+
+mod tests {
+    use crate::secp256r1::*;
+    use crate::curve::{Point, WeierstrassCurve};
+
+    #[test]
+    fn secp256r1_double_and_add_matches_scalar_mul() {
+        let g2 = SECP256R1::double_point(&SECP256R1::g());
+        let g3 = SECP256R1::add_points(&g2, &SECP256R1::g());
+
+        // 3G should equal G doubled-and-added back onto itself via the ladder
+        assert_eq!(g3.to_hex_string(), SECP256R1::pr_to_pub(&RU256::from_str("0x3").unwrap()).to_hex_string());
+    }
+
+    // Independent NIST P-256 ECDSA vector (message "sample", SHA-256),
+    // generated and verified offline against a production P-256
+    // implementation -- exercises the generalized `a != 0` doubling
+    // formula rather than checking the new code against itself.
+    #[test]
+    fn secp256r1_verify_known_vector() {
+        let pub_key = Point::from_hex_coordinates(
+            "439ED13599D6E4F6CE33118B0421D0630E57C6919F6E0A8068C3C85A0C2412BF",
+            "432D079A174B0C2F453FD6CF1D45078F30FE3DD1DF73564340371BE7DD545FB3"
+        );
+        let msg_hash = RU256::from_str("AF2BDBE1AA9B6EC1E2ADE1D694F41FC71A831D0268E9891562113D8A62ADD1BF").unwrap();
+        let r = RU256::from_str("92241719C56B2A29DEB6C1A1E99855D457BF0B1DB8AB264F0185B3D6DB62BA01").unwrap();
+        let s = RU256::from_str("F690DD9BA0393D12B0184020BEACD73FDBA6D3BA33DBC6350441CE5FC83551CB").unwrap();
+
+        assert!(SECP256R1::verify(&pub_key, &msg_hash, &(r, s)));
+    }
+}
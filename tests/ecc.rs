@@ -2,9 +2,11 @@ use std::str::FromStr;
 
 use walletcryptography::ru256::RU256;
 use walletcryptography::secp256k1::*;
+use walletcryptography::curve::{Point, Sec1Error, WeierstrassCurve};
 use walletcryptography::base16;
 
-use secp256k1::{Secp256k1, SecretKey, PublicKey};
+use secp256k1::{Secp256k1, SecretKey, PublicKey, Message};
+use secp256k1::ecdsa::RecoverableSignature;
 use rand::prelude::*;
 
 #[test]
@@ -29,3 +31,112 @@ fn ecc() {
     assert_eq!(pub_key_str1, pub_key_str2);
 }
 
+#[test]
+#[ignore]
+fn ecdsa_sign_verify() {
+    // generate a random private key and a random message hash
+    let mut rng = rand::thread_rng();
+    let pr_n = hex::encode((0..32).map(|_| rng.gen_range(0..=255)).collect::<Vec<u8>>());
+    let msg_n = hex::encode((0..32).map(|_| rng.gen_range(0..=255)).collect::<Vec<u8>>());
+
+    let priv_key = RU256::from_str(&pr_n).unwrap();
+    let msg_hash = RU256::from_str(&msg_n).unwrap();
+
+    // sign with custom-wrote ECDSA + RFC6979
+    let (r, s) = SECP256K1::sign(&priv_key, &msg_hash);
+    let mut sig_bytes = [0u8; 64];
+    r.to_bytes((&mut sig_bytes[0..32]).try_into().unwrap());
+    s.to_bytes((&mut sig_bytes[32..64]).try_into().unwrap());
+
+    // sign with production library (also RFC6979-deterministic by default)
+    let secp = Secp256k1::new();
+    let pr_key = SecretKey::from_str(&pr_n).expect("private-key");
+    let message = Message::from_digest_slice(&hex::decode(&msg_n).unwrap()).unwrap();
+    let sig2 = secp.sign_ecdsa(&message, &pr_key);
+
+    assert_eq!(base16::encode_bytes(&sig_bytes), base16::encode_bytes(&sig2.serialize_compact()));
+
+    // verify round-trip against our own curve arithmetic
+    let pub_key = SECP256K1::pr_to_pub(&priv_key);
+    assert!(SECP256K1::verify(&pub_key, &msg_hash, &(r, s)));
+}
+
+#[test]
+#[ignore]
+fn compressed_pubkey_round_trip() {
+    let mut rng = rand::thread_rng();
+    let pr_n = hex::encode((0..32).map(|_| rng.gen_range(0..=255)).collect::<Vec<u8>>());
+    let priv_key = RU256::from_str(&pr_n).unwrap();
+
+    let pub_key = SECP256K1::pr_to_pub(&priv_key);
+    let compressed_hex = pub_key.to_compressed_hex();
+
+    // compare against the production library's compressed serialization
+    let secp = Secp256k1::new();
+    let pr_key = SecretKey::from_str(&pr_n).expect("private-key");
+    let pub_key2 = PublicKey::from_secret_key(&secp, &pr_key);
+    assert_eq!(compressed_hex, base16::encode_bytes(&pub_key2.serialize()));
+
+    // and that decompressing it recovers the original point
+    let compressed_bytes = hex::decode(&compressed_hex).unwrap();
+    let decompressed = SECP256K1::from_sec1(&compressed_bytes).unwrap();
+    assert_eq!(decompressed.to_hex_string(), pub_key.to_hex_string());
+}
+
+#[test]
+fn uncompressed_off_curve_point_is_rejected() {
+    // a valid x with an arbitrary y that is not on the curve
+    let pub_key = SECP256K1::pr_to_pub(&RU256::from_str("0x1").unwrap());
+    let mut off_curve_bytes = hex::decode(pub_key.to_hex_string()).unwrap();
+    *off_curve_bytes.last_mut().unwrap() ^= 0x01;
+
+    assert!(matches!(SECP256K1::from_sec1(&off_curve_bytes), Err(Sec1Error::NotOnCurve)));
+}
+
+#[test]
+fn sec1_wrong_length_is_distinguished_from_unrecognized_tag() {
+    let pub_key = SECP256K1::pr_to_pub(&RU256::from_str("0x1").unwrap());
+
+    // a 0x04 tag (otherwise valid) but truncated to the wrong length
+    let mut truncated_uncompressed = hex::decode(pub_key.to_hex_string()).unwrap();
+    truncated_uncompressed.pop();
+    assert!(matches!(SECP256K1::from_sec1(&truncated_uncompressed), Err(Sec1Error::InvalidLength)));
+
+    // a 0x02 tag (otherwise valid) but truncated to the wrong length
+    let mut truncated_compressed = hex::decode(pub_key.to_compressed_hex()).unwrap();
+    truncated_compressed.pop();
+    assert!(matches!(SECP256K1::from_sec1(&truncated_compressed), Err(Sec1Error::InvalidLength)));
+
+    // a genuinely unrecognized tag byte should still report InvalidTag
+    let mut bad_tag = hex::decode(pub_key.to_compressed_hex()).unwrap();
+    bad_tag[0] = 0x05;
+    assert!(matches!(SECP256K1::from_sec1(&bad_tag), Err(Sec1Error::InvalidTag)));
+}
+
+#[test]
+#[ignore]
+fn ecdsa_recover() {
+    let mut rng = rand::thread_rng();
+    let pr_n = hex::encode((0..32).map(|_| rng.gen_range(0..=255)).collect::<Vec<u8>>());
+    let msg_n = hex::encode((0..32).map(|_| rng.gen_range(0..=255)).collect::<Vec<u8>>());
+
+    let priv_key = RU256::from_str(&pr_n).unwrap();
+    let msg_hash = RU256::from_str(&msg_n).unwrap();
+
+    let (r, s, recovery_id) = SECP256K1::sign_recoverable(&priv_key, &msg_hash);
+    let recovered = SECP256K1::recover(&msg_hash, &(r, s), recovery_id).unwrap();
+
+    // the production library's recovery id for the same deterministic
+    // signature should match ours, and its recovered key should match
+    let secp = Secp256k1::new();
+    let pr_key = SecretKey::from_str(&pr_n).expect("private-key");
+    let message = Message::from_digest_slice(&hex::decode(&msg_n).unwrap()).unwrap();
+    let sig2 = secp.sign_ecdsa_recoverable(&message, &pr_key);
+    let (recid2, _) = sig2.serialize_compact();
+
+    assert_eq!(recovery_id, recid2.to_i32() as u8);
+
+    let pub_key2: PublicKey = RecoverableSignature::recover(&sig2, &message).unwrap();
+    assert_eq!(recovered.to_hex_string(), base16::encode_bytes(&pub_key2.serialize_uncompressed()));
+}
+